@@ -161,6 +161,19 @@ fn bytes_mut_extend(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn extend_from_exact_size_iter(b: &mut Bencher) {
+    const LEN: usize = 1024 * 1024;
+    let data = vec![33u8; LEN];
+
+    b.bytes = LEN as u64;
+    b.iter(|| {
+        let mut buf = BytesMut::new();
+        buf.extend(data.clone());
+        test::black_box(&buf);
+    });
+}
+
 // BufMut for BytesMut vs Vec<u8>
 
 #[bench]
@@ -0,0 +1,163 @@
+use core::cmp;
+
+use crate::Buf;
+
+/// A `Buf` adapter which reads individual bits, rather than whole bytes.
+///
+/// This is useful for protocols that pack fields that aren't a whole number
+/// of bytes wide (audio codecs, Huffman-coded data, and similar). `BitReader`
+/// pulls bytes from the underlying [`Buf`] as needed and keeps track of how
+/// many bits of the current byte have already been consumed.
+///
+/// This struct is generally created by calling [`BitReader::new`].
+///
+/// # Examples
+///
+/// ```
+/// use bytes::buf::BitReader;
+///
+/// let mut bits = BitReader::new(&b"\xB4"[..]);
+///
+/// assert_eq!(0b101, bits.read_bits(3));
+/// assert_eq!(0b10100, bits.read_bits(5));
+/// ```
+#[derive(Debug)]
+pub struct BitReader<B> {
+    buf: B,
+    // The partially-consumed byte, if any.
+    cur: u8,
+    // The number of unconsumed bits remaining in `cur`.
+    bits_left: u8,
+}
+
+impl<B> BitReader<B> {
+    /// Creates a new `BitReader` reading bits from `buf`.
+    pub fn new(buf: B) -> BitReader<B> {
+        BitReader {
+            buf,
+            cur: 0,
+            bits_left: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying `Buf`.
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Gets a mutable reference to the underlying `Buf`.
+    ///
+    /// It is inadvisable to directly read from the underlying `Buf` while
+    /// bits of the current byte have not yet been consumed; call
+    /// [`align`](Self::align) first.
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Consumes this `BitReader`, returning the underlying value.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    /// Discards any unread bits of the current partial byte, so that the
+    /// next read starts at a byte boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::buf::BitReader;
+    ///
+    /// let mut bits = BitReader::new(&b"\xFF\x00"[..]);
+    ///
+    /// assert_eq!(0b1111, bits.read_bits(4));
+    /// bits.align();
+    /// assert_eq!(0, bits.read_bits(8));
+    /// ```
+    pub fn align(&mut self) {
+        self.bits_left = 0;
+    }
+}
+
+impl<B: Buf> BitReader<B> {
+    /// Reads `n` bits (`0..=64`) from `self`, MSB-first, and returns them
+    /// right-aligned in a `u64`.
+    ///
+    /// Bits are read from the underlying `Buf` one byte at a time via
+    /// [`Buf::get_u8`]; a field may straddle a byte boundary.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n > 64`, or if the underlying `Buf` does not
+    /// have enough remaining bits.
+    pub fn read_bits(&mut self, n: u8) -> u64 {
+        assert!(n <= 64, "cannot read more than 64 bits at once");
+
+        let mut out: u64 = 0;
+        let mut remaining = n;
+
+        while remaining > 0 {
+            if self.bits_left == 0 {
+                self.cur = self.buf.get_u8();
+                self.bits_left = 8;
+            }
+
+            let take = cmp::min(remaining, self.bits_left);
+            let shift = self.bits_left - take;
+            let mask = mask_u8(take);
+            let bits = (self.cur >> shift) & mask;
+
+            out = (out << take) | u64::from(bits);
+
+            self.bits_left -= take;
+            remaining -= take;
+        }
+
+        out
+    }
+
+    /// Reads `n` bits (`0..=64`) from `self`, LSB-first, and returns them
+    /// right-aligned in a `u64`.
+    ///
+    /// Unlike [`read_bits`](Self::read_bits), within each byte bits are
+    /// consumed starting from the least-significant bit, and the first bit
+    /// read becomes the least-significant bit of the result.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n > 64`, or if the underlying `Buf` does not
+    /// have enough remaining bits.
+    pub fn read_bits_lsb(&mut self, n: u8) -> u64 {
+        assert!(n <= 64, "cannot read more than 64 bits at once");
+
+        let mut out: u64 = 0;
+        let mut filled: u8 = 0;
+        let mut remaining = n;
+
+        while remaining > 0 {
+            if self.bits_left == 0 {
+                self.cur = self.buf.get_u8();
+                self.bits_left = 8;
+            }
+
+            let take = cmp::min(remaining, self.bits_left);
+            let bits = self.cur & mask_u8(take);
+
+            out |= u64::from(bits) << filled;
+
+            self.cur = self.cur.checked_shr(u32::from(take)).unwrap_or(0);
+            self.bits_left -= take;
+            filled += take;
+            remaining -= take;
+        }
+
+        out
+    }
+}
+#[inline]
+fn mask_u8(bits: u8) -> u8 {
+    if bits >= 8 {
+        0xFF
+    } else {
+        (1u8 << bits) - 1
+    }
+}
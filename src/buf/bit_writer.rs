@@ -0,0 +1,138 @@
+use core::cmp;
+
+use crate::BufMut;
+
+/// A `BufMut` adapter which writes individual bits, rather than whole bytes.
+///
+/// This is the write-side counterpart to [`BitReader`](crate::buf::BitReader),
+/// useful for emitting fields that aren't a whole number of bytes wide (e.g.
+/// a 3-bit tag followed by a 13-bit length). Bits are accumulated into a
+/// partial byte and flushed to the underlying [`BufMut`] via
+/// [`BufMut::put_u8`] once 8 bits have been written.
+///
+/// This struct is generally created by calling [`BitWriter::new`]. Callers
+/// must call [`flush`](Self::flush) once done writing, or any bits of a
+/// trailing partial byte will be lost.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::buf::BitWriter;
+///
+/// let mut bits = BitWriter::new(Vec::new());
+///
+/// bits.write_bits(0b101, 3);
+/// bits.write_bits(0b10100, 5);
+/// bits.flush();
+///
+/// assert_eq!(bits.into_inner(), vec![0b1011_0100]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<B> {
+    buf: B,
+    // The partially-filled byte, not yet written to `buf`.
+    cur: u8,
+    // The number of bits of `cur` that have been filled in so far.
+    bits_filled: u8,
+}
+
+impl<B> BitWriter<B> {
+    /// Creates a new `BitWriter` writing bits to `buf`.
+    pub fn new(buf: B) -> BitWriter<B> {
+        BitWriter {
+            buf,
+            cur: 0,
+            bits_filled: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying `BufMut`.
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Gets a mutable reference to the underlying `BufMut`.
+    ///
+    /// It is inadvisable to directly write to the underlying `BufMut` while
+    /// bits of the current byte have not yet been flushed; call
+    /// [`flush`](Self::flush) first.
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Consumes this `BitWriter`, returning the underlying value.
+    ///
+    /// Any unflushed bits of a trailing partial byte are discarded; call
+    /// [`flush`](Self::flush) first if they should be emitted.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: BufMut> BitWriter<B> {
+    /// Writes the low `n` bits (`0..=64`) of `value` to `self`, MSB-first.
+    ///
+    /// Complete bytes are flushed to the underlying `BufMut` as they fill up;
+    /// any trailing partial byte is held until the next write, [`flush`], or
+    /// [`align`].
+    ///
+    /// [`flush`]: Self::flush
+    /// [`align`]: Self::align
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n > 64`.
+    pub fn write_bits(&mut self, value: u64, n: u8) {
+        assert!(n <= 64, "cannot write more than 64 bits at once");
+
+        let mut remaining = n;
+
+        while remaining > 0 {
+            let free = 8 - self.bits_filled;
+            let take = cmp::min(remaining, free);
+            let shift = remaining - take;
+            let bits = ((value >> shift) & mask_u64(take)) as u8;
+
+            self.cur |= bits << (free - take);
+            self.bits_filled += take;
+            remaining -= take;
+
+            if self.bits_filled == 8 {
+                self.buf.put_u8(self.cur);
+                self.cur = 0;
+                self.bits_filled = 0;
+            }
+        }
+    }
+
+    /// Pads the current partial byte with zero bits and emits it to the
+    /// underlying `BufMut`, so that the next write starts at a byte
+    /// boundary.
+    ///
+    /// This is a no-op if `self` is already aligned to a byte boundary.
+    pub fn align(&mut self) {
+        if self.bits_filled > 0 {
+            self.buf.put_u8(self.cur);
+            self.cur = 0;
+            self.bits_filled = 0;
+        }
+    }
+
+    /// Flushes any unwritten bits of a trailing partial byte, zero-padded, to
+    /// the underlying `BufMut`.
+    ///
+    /// This must be called once writing is finished, or the bits of a
+    /// trailing partial byte will never reach the underlying `BufMut`.
+    pub fn flush(&mut self) {
+        self.align();
+    }
+}
+
+#[inline]
+fn mask_u64(bits: u8) -> u64 {
+    if bits >= 64 {
+        !0u64
+    } else {
+        (1u64 << bits) - 1
+    }
+}
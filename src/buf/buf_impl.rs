@@ -1144,6 +1144,36 @@ pub trait Buf {
         ret.freeze()
     }
 
+    /// Drains `self` into `dst`, appending all of its remaining bytes.
+    ///
+    /// This is similar to `dst.put(self)`, but it reserves `self.remaining()`
+    /// on `dst` exactly once up front, rather than relying on `dst` to grow
+    /// itself as each chunk is written. `self` is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::{Buf, BytesMut};
+    ///
+    /// let mut buf = &b"hello world"[..];
+    /// let mut dst = BytesMut::new();
+    ///
+    /// buf.append_to(&mut dst);
+    ///
+    /// assert_eq!(dst, b"hello world"[..]);
+    /// assert!(!buf.has_remaining());
+    /// ```
+    fn append_to(&mut self, dst: &mut crate::BytesMut) {
+        dst.reserve(self.remaining());
+
+        while self.has_remaining() {
+            let chunk = self.chunk();
+            let len = chunk.len();
+            dst.extend_from_slice(chunk);
+            self.advance(len);
+        }
+    }
+
     /// Creates an adaptor which will read at most `limit` bytes from `self`.
     ///
     /// This function returns a new instance of `Buf` which will read at most
@@ -1394,6 +1424,11 @@ macro_rules! deref_forward_buf {
         fn copy_to_bytes(&mut self, len: usize) -> crate::Bytes {
             (**self).copy_to_bytes(len)
         }
+
+        #[inline]
+        fn append_to(&mut self, dst: &mut crate::BytesMut) {
+            (**self).append_to(dst)
+        }
     };
 }
 
@@ -1465,6 +1500,16 @@ impl<T: AsRef<[u8]>> Buf for std::io::Cursor<T> {
         // greater than `len`.
         self.set_position(pos + cnt as u64);
     }
+
+    fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        let chunk = self.chunk();
+        if chunk.len() < dst.len() {
+            panic_advance(dst.len(), self.remaining());
+        }
+
+        dst.copy_from_slice(&chunk[..dst.len()]);
+        self.advance(dst.len());
+    }
 }
 
 // The existence of this function makes the compiler catch if the Buf
@@ -14,6 +14,8 @@
 //!
 //! [rope]: https://en.wikipedia.org/wiki/Rope_(data_structure)
 
+mod bit_reader;
+mod bit_writer;
 mod buf_impl;
 mod buf_mut;
 mod chain;
@@ -27,6 +29,8 @@ mod vec_deque;
 #[cfg(feature = "std")]
 mod writer;
 
+pub use self::bit_reader::BitReader;
+pub use self::bit_writer::BitWriter;
 pub use self::buf_impl::Buf;
 pub use self::buf_mut::BufMut;
 pub use self::chain::Chain;
@@ -16,7 +16,7 @@ use crate::buf::IntoIter;
 #[allow(unused)]
 use crate::loom::sync::atomic::AtomicMut;
 use crate::loom::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
-use crate::{offset_from, Buf, BytesMut};
+use crate::{offset_from, panic_advance, Buf, BytesMut};
 
 /// A cheaply cloneable and sliceable chunk of contiguous memory.
 ///
@@ -343,6 +343,48 @@ impl Bytes {
         unsafe { (self.vtable.is_unique)(&self.data) }
     }
 
+    /// Returns the underlying `'static` slice if this `Bytes` is backed by
+    /// one, or `None` otherwise.
+    ///
+    /// A `Bytes` is static-backed if it (or a `Bytes` it was sliced or cloned
+    /// from) was created via [`Bytes::from_static`] (which the
+    /// `From<&'static [u8]>` and `From<&'static str>` impls use) or
+    /// [`Bytes::new`]. An empty `Bytes` produced by `slice`, `split_off` or
+    /// `split_to` is static-backed as well, whatever it was split from, so
+    /// this returns `Some` of an empty slice for it. Otherwise this returns
+    /// `None` for `Bytes` backed by a heap allocation (e.g. those created from
+    /// a `Vec<u8>`) or by an [owner](Bytes::from_owner), even though such a
+    /// `Bytes` may itself be referenced from `'static` storage; there is no
+    /// way to distinguish that case from a `Bytes` with a shorter lifetime.
+    ///
+    /// This is useful for recovering the `'static` lifetime of a handle known
+    /// to be static-backed, to pass to an API that requires it without
+    /// copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    ///
+    /// let a = Bytes::from_static(b"hello");
+    /// assert_eq!(a.as_static(), Some(&b"hello"[..]));
+    ///
+    /// let b = Bytes::from(b"hello".to_vec());
+    /// assert_eq!(b.as_static(), None);
+    /// ```
+    #[inline]
+    pub fn as_static(&self) -> Option<&'static [u8]> {
+        if ptr::eq(self.vtable, &STATIC_VTABLE) {
+            // Safety: a `STATIC_VTABLE`-backed `Bytes` either views part of
+            // the `&'static [u8]` it was created from, or is empty (see
+            // `new_empty_with_ptr`), and a zero-length slice may be built
+            // from any non-null pointer.
+            Some(unsafe { slice::from_raw_parts(self.ptr, self.len) })
+        } else {
+            None
+        }
+    }
+
     /// Creates `Bytes` instance from slice, by copying it.
     pub fn copy_from_slice(data: &[u8]) -> Self {
         data.to_vec().into()
@@ -723,6 +765,29 @@ impl Buf for Bytes {
     fn copy_to_bytes(&mut self, len: usize) -> Self {
         self.split_to(len)
     }
+
+    fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        if self.len() < dst.len() {
+            panic_advance(dst.len(), self.len());
+        }
+
+        dst.copy_from_slice(&self.as_slice()[..dst.len()]);
+        self.advance(dst.len());
+    }
+
+    fn append_to(&mut self, dst: &mut BytesMut) {
+        // If `dst` is empty, we can just hand it the backing storage of
+        // `self` instead of copying into `dst`'s (possibly nonexistent)
+        // allocation. `BytesMut::from` already does this without copying
+        // whenever `self` uniquely owns its storage.
+        if dst.is_empty() {
+            *dst = BytesMut::from(mem::replace(self, Bytes::new()));
+            return;
+        }
+
+        dst.extend_from_slice(self.as_slice());
+        *self = Bytes::new();
+    }
 }
 
 impl Deref for Bytes {
@@ -1075,7 +1140,7 @@ impl fmt::Debug for Vtable {
 
 // ===== impl StaticVtable =====
 
-const STATIC_VTABLE: Vtable = Vtable {
+static STATIC_VTABLE: Vtable = Vtable {
     clone: static_clone,
     to_vec: static_to_vec,
     to_mut: static_to_mut,
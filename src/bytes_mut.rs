@@ -264,6 +264,45 @@ impl BytesMut {
         }
     }
 
+    /// Converts `self` into an immutable `Bytes`, also returning the backing
+    /// allocation's capacity.
+    ///
+    /// This is identical to [`freeze`](BytesMut::freeze), except it also
+    /// returns the capacity of the whole allocation backing `self` as it
+    /// stood right before the conversion. This can be larger than
+    /// [`capacity`](BytesMut::capacity) when `self` has been advanced or split
+    /// off from a larger buffer. This is useful for pooling schemes that need to account
+    /// for how much memory a frozen handle pins, since the returned `Bytes`
+    /// has no way to report it on its own. Note that the reported capacity is
+    /// the size of the allocation, which may be larger than the handle's
+    /// length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    ///
+    /// let mut b = BytesMut::with_capacity(64);
+    /// b.extend_from_slice(b"hello world");
+    ///
+    /// let (bytes, capacity) = b.freeze_with_capacity();
+    /// assert_eq!(&bytes[..], b"hello world");
+    /// assert_eq!(capacity, 64);
+    /// ```
+    #[inline]
+    pub fn freeze_with_capacity(self) -> (Bytes, usize) {
+        let capacity = if self.kind() == KIND_VEC {
+            // SAFETY: `self` is in vec mode, so the bytes before `ptr` that
+            // belong to the allocation are counted by `get_vec_pos`.
+            unsafe { self.get_vec_pos() + self.cap }
+        } else {
+            // SAFETY: `self` is in shared mode, so `data` points to a live
+            // `Shared` that owns the whole allocation.
+            unsafe { (*self.data).vec.capacity() }
+        };
+        (self.freeze(), capacity)
+    }
+
     /// Creates a new `BytesMut` containing `len` zeros.
     ///
     /// The resulting object has a length of `len` and a capacity greater
@@ -530,6 +569,58 @@ impl BytesMut {
         self.len = len;
     }
 
+    /// Reserves capacity for at least `additional` more bytes, extends the
+    /// length of the buffer by `additional`, and returns the newly-exposed
+    /// region so the caller can fill it in.
+    ///
+    /// This packages the `reserve` + `set_len` + [`spare_capacity_mut`] dance
+    /// used to append data in place into a single call.
+    ///
+    /// [`spare_capacity_mut`]: BytesMut::spare_capacity_mut
+    ///
+    /// Note that this cannot be a safe method, even though it returns
+    /// `&mut [MaybeUninit<u8>]` rather than exposing initialized memory
+    /// directly: it has to extend `len` *before* the caller has written
+    /// anything, so that the returned slice lands at the right offset and
+    /// has the right length. A safe caller could simply not finish filling
+    /// in the slice and then read the buffer through any of the ordinary
+    /// safe APIs (e.g. `Deref<Target = [u8]>`), observing uninitialized
+    /// memory. So, unlike `spare_capacity_mut` (which never advances `len`),
+    /// this still has to be `unsafe`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must initialize every byte of the returned slice before
+    /// the buffer's new length is observed through any other method, since
+    /// doing so would expose uninitialized memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    ///
+    /// let mut buf = BytesMut::with_capacity(10);
+    ///
+    /// unsafe {
+    ///     let uninit = buf.grow_uninit(3);
+    ///     uninit[0].write(0);
+    ///     uninit[1].write(1);
+    ///     uninit[2].write(2);
+    /// }
+    ///
+    /// assert_eq!(&buf[..], &[0, 1, 2]);
+    /// ```
+    #[inline]
+    pub unsafe fn grow_uninit(&mut self, additional: usize) -> &mut [MaybeUninit<u8>] {
+        self.reserve(additional);
+
+        let old_len = self.len;
+        self.set_len(old_len + additional);
+
+        let ptr = self.ptr.as_ptr().add(old_len);
+        slice::from_raw_parts_mut(ptr.cast(), additional)
+    }
+
     /// Reserves capacity for at least `additional` more bytes to be inserted
     /// into the given `BytesMut`.
     ///
@@ -1166,6 +1257,15 @@ impl Buf for BytesMut {
     fn copy_to_bytes(&mut self, len: usize) -> Bytes {
         self.split_to(len).freeze()
     }
+
+    fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        if self.len() < dst.len() {
+            super::panic_advance(dst.len(), self.len());
+        }
+
+        dst.copy_from_slice(&self.as_slice()[..dst.len()]);
+        self.advance(dst.len());
+    }
 }
 
 unsafe impl BufMut for BytesMut {
@@ -1368,15 +1468,38 @@ impl Extend<u8> for BytesMut {
     where
         T: IntoIterator<Item = u8>,
     {
-        let iter = iter.into_iter();
+        let mut iter = iter.into_iter();
 
         let (lower, _) = iter.size_hint();
         self.reserve(lower);
 
         // TODO: optimize
         // 1. If self.kind() == KIND_VEC, use Vec::extend
-        for b in iter {
-            self.put_u8(b);
+        //
+        // Write directly into the spare capacity instead of calling
+        // `reserve` for every byte; only reserve again, in bulk, once the
+        // spare capacity found above has been exhausted.
+        while let Some(b) = iter.next() {
+            let mut dst = self.spare_capacity_mut();
+            if dst.is_empty() {
+                self.reserve(64);
+                dst = self.spare_capacity_mut();
+            }
+
+            dst[0] = MaybeUninit::new(b);
+            let mut written = 1;
+            for slot in &mut dst[1..] {
+                match iter.next() {
+                    Some(b) => {
+                        *slot = MaybeUninit::new(b);
+                        written += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            // SAFETY: `written` bytes were just initialized above.
+            unsafe { self.advance_mut(written) };
         }
     }
 }
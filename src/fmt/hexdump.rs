@@ -0,0 +1,83 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{Bytes, BytesMut};
+
+const BYTES_PER_LINE: usize = 16;
+
+/// A `Display`-only view of a byte buffer's contents as a classic
+/// `xxd`-style hex dump.
+///
+/// Each line shows, from left to right:
+///
+/// - the 8-digit hex offset of the first byte on that line,
+/// - up to eight space-separated two-byte hex groups (16 bytes total), and
+/// - an ASCII gutter, where printable ASCII bytes are shown as-is and all
+///   other bytes are rendered as `.`.
+///
+/// The dump is only computed when this value is actually formatted, e.g.
+/// with `println!` or `.to_string()`.
+///
+/// Returned by [`Bytes::hexdump`] and [`BytesMut::hexdump`].
+#[derive(Debug)]
+pub struct HexDump<'a>(&'a [u8]);
+
+impl Display for HexDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (line_no, line) in self.0.chunks(BYTES_PER_LINE).enumerate() {
+            write!(f, "{:08x}: ", line_no * BYTES_PER_LINE)?;
+
+            for i in (0..BYTES_PER_LINE).step_by(2) {
+                match (line.get(i), line.get(i + 1)) {
+                    (Some(a), Some(b)) => write!(f, "{:02x}{:02x} ", a, b)?,
+                    (Some(a), None) => write!(f, "{:02x}   ", a)?,
+                    (None, _) => write!(f, "     ")?,
+                }
+            }
+
+            write!(f, " ")?;
+
+            for &b in line {
+                if (0x20..0x7f).contains(&b) {
+                    write!(f, "{}", b as char)?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! hexdump_impl {
+    ($ty:ty) => {
+        impl $ty {
+            /// Returns an object that implements `Display` for printing the
+            /// contents of `self` as a hex dump, in the style of `xxd`.
+            ///
+            /// Unlike [`Debug`](core::fmt::Debug), this does not truncate or
+            /// escape the contents, which makes it more useful for
+            /// inspecting real binary payloads.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use bytes::Bytes;
+            ///
+            /// let buf = Bytes::from_static(b"hello world!\n");
+            /// assert_eq!(
+            ///     buf.hexdump().to_string(),
+            ///     "00000000: 6865 6c6c 6f20 776f 726c 6421 0a         hello world!.\n",
+            /// );
+            /// ```
+            pub fn hexdump(&self) -> HexDump<'_> {
+                HexDump(self.as_ref())
+            }
+        }
+    };
+}
+
+hexdump_impl!(Bytes);
+hexdump_impl!(BytesMut);
@@ -10,6 +10,9 @@ macro_rules! fmt_impl {
 
 mod debug;
 mod hex;
+mod hexdump;
+
+pub use self::hexdump::HexDump;
 
 /// `BytesRef` is not a part of public API of bytes crate.
 struct BytesRef<'a>(&'a [u8]);
@@ -86,6 +86,7 @@ mod fmt;
 mod loom;
 pub use crate::bytes::Bytes;
 pub use crate::bytes_mut::BytesMut;
+pub use crate::fmt::HexDump;
 
 // Optional Serde support
 #[cfg(feature = "serde")]
@@ -0,0 +1,69 @@
+#![warn(rust_2018_idioms)]
+
+use bytes::buf::BitReader;
+
+#[test]
+fn read_bits_width_1() {
+    // 0b1010_1100
+    let mut bits = BitReader::new(&b"\xAC"[..]);
+
+    let mut out = Vec::new();
+    for _ in 0..8 {
+        out.push(bits.read_bits(1));
+    }
+    assert_eq!(out, [1, 0, 1, 0, 1, 1, 0, 0]);
+}
+
+#[test]
+fn read_bits_width_7_across_boundary() {
+    // 0b1001_1010 0b1100_0011
+    let mut bits = BitReader::new(&b"\x9A\xC3"[..]);
+
+    assert_eq!(0b1001_101, bits.read_bits(7));
+    assert_eq!(0b0_1100_00, bits.read_bits(7));
+    assert_eq!(0b11, bits.read_bits(2));
+}
+
+#[test]
+fn read_bits_width_9_across_boundary() {
+    let mut bits = BitReader::new(&b"\xFF\x01\x00"[..]);
+
+    assert_eq!(0x1FE, bits.read_bits(9));
+    assert_eq!(0b0_0000_0100, bits.read_bits(9));
+}
+
+#[test]
+fn read_bits_width_64() {
+    let data = 0x0123_4567_89AB_CDEFu64.to_be_bytes();
+    let mut bits = BitReader::new(&data[..]);
+
+    assert_eq!(0x0123_4567_89AB_CDEF, bits.read_bits(64));
+}
+
+#[test]
+fn align_discards_partial_byte() {
+    let mut bits = BitReader::new(&b"\xFF\x00"[..]);
+
+    assert_eq!(0b1111, bits.read_bits(4));
+    bits.align();
+    assert_eq!(0, bits.read_bits(8));
+}
+
+#[test]
+fn read_bits_lsb_width_7_across_boundary() {
+    let mut bits = BitReader::new(&[0b1001_1010u8, 0b1100_0011u8][..]);
+
+    // First 7 bits, LSB-first, of 0b1001_1010 are bits 0..=6: 0b0011010.
+    assert_eq!(0b001_1010, bits.read_bits_lsb(7));
+    // Remaining bit of the first byte (bit 7 = 1) followed by the low 6
+    // bits of the second byte (0b00_0011), LSB-first, combine to 0b000011_1.
+    assert_eq!(0b000011_1, bits.read_bits_lsb(7));
+    assert_eq!(0b11, bits.read_bits_lsb(2));
+}
+
+#[test]
+#[should_panic]
+fn read_bits_too_wide_panics() {
+    let mut bits = BitReader::new(&[0u8; 16][..]);
+    bits.read_bits(65);
+}
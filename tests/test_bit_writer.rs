@@ -0,0 +1,83 @@
+#![warn(rust_2018_idioms)]
+
+use bytes::buf::{BitReader, BitWriter};
+
+#[test]
+fn write_bits_round_trips_byte_aligned() {
+    let mut bits = BitWriter::new(Vec::new());
+
+    bits.write_bits(0b101, 3);
+    bits.write_bits(0b10100, 5);
+    bits.flush();
+
+    let out = bits.into_inner();
+    assert_eq!(out, vec![0b1011_0100]);
+
+    let mut bits = BitReader::new(&out[..]);
+    assert_eq!(0b101, bits.read_bits(3));
+    assert_eq!(0b10100, bits.read_bits(5));
+}
+
+#[test]
+fn write_bits_round_trips_varied_widths() {
+    let fields: &[(u64, u8)] = &[
+        (0b1, 1),
+        (0b1101, 4),
+        (0x1FE, 9),
+        (0xABCDEF, 24),
+        (0x0123_4567_89AB_CDEF, 64),
+        (0b11, 2),
+    ];
+
+    let mut bits = BitWriter::new(Vec::new());
+    for &(value, width) in fields {
+        bits.write_bits(value, width);
+    }
+    bits.flush();
+
+    let out = bits.into_inner();
+
+    let mut bits = BitReader::new(&out[..]);
+    for &(value, width) in fields {
+        assert_eq!(value, bits.read_bits(width));
+    }
+}
+
+#[test]
+fn flush_zero_pads_trailing_partial_byte() {
+    let mut bits = BitWriter::new(Vec::new());
+
+    // A 3-bit field followed by a 1-bit field: 4 bits in all, which doesn't
+    // end on a byte boundary, so `flush` has to zero-pad the rest of the byte.
+    bits.write_bits(0b110, 3);
+    bits.write_bits(0b1, 1);
+    bits.flush();
+
+    let out = bits.into_inner();
+    assert_eq!(out, vec![0b1101_0000]);
+
+    let mut reader = BitReader::new(&out[..]);
+    assert_eq!(0b110, reader.read_bits(3));
+    assert_eq!(0b1, reader.read_bits(1));
+    assert_eq!(0, reader.read_bits(4));
+}
+
+#[test]
+fn align_emits_partial_byte_and_resumes_at_boundary() {
+    let mut bits = BitWriter::new(Vec::new());
+
+    bits.write_bits(0b1111, 4);
+    bits.align();
+    bits.write_bits(0xAB, 8);
+    bits.flush();
+
+    let out = bits.into_inner();
+    assert_eq!(out, vec![0b1111_0000, 0xAB]);
+}
+
+#[test]
+#[should_panic]
+fn write_bits_too_wide_panics() {
+    let mut bits = BitWriter::new(Vec::new());
+    bits.write_bits(0, 65);
+}
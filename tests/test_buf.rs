@@ -1,6 +1,6 @@
 #![warn(rust_2018_idioms)]
 
-use bytes::Buf;
+use bytes::{Buf, Bytes, BytesMut};
 #[cfg(feature = "std")]
 use std::io::IoSlice;
 
@@ -85,6 +85,103 @@ fn test_vec_deque() {
     assert_eq!(b"world piece", &out[..]);
 }
 
+#[test]
+fn test_copy_to_slice_chunk_larger_than_dst() {
+    use std::collections::VecDeque;
+
+    // `VecDeque` relies on the default `copy_to_slice` impl, which loops
+    // over chunks. Exercise the case where a single chunk is larger than
+    // `dst`, so each call copies only part of that one chunk.
+    let mut buffer: VecDeque<u8> = VecDeque::new();
+    buffer.extend(b"hello world");
+    assert_eq!(buffer.chunk(), b"hello world");
+
+    let mut out = [0; 5];
+    buffer.copy_to_slice(&mut out);
+    assert_eq!(b"hello", &out[..]);
+    assert_eq!(buffer.chunk(), b" world");
+
+    let mut out = [0; 6];
+    buffer.copy_to_slice(&mut out);
+    assert_eq!(b" world", &out[..]);
+    assert_eq!(0, buffer.remaining());
+}
+
+#[test]
+fn test_copy_to_slice_bytes() {
+    let mut buf = Bytes::from_static(b"hello world");
+
+    let mut out = [0; 5];
+    buf.copy_to_slice(&mut out);
+    assert_eq!(b"hello", &out[..]);
+    assert_eq!(buf.chunk(), b" world");
+
+    let mut out = [0; 6];
+    buf.copy_to_slice(&mut out);
+    assert_eq!(b" world", &out[..]);
+    assert_eq!(0, buf.remaining());
+}
+
+#[test]
+#[should_panic]
+fn test_copy_to_slice_bytes_panics_on_underflow() {
+    let mut buf = Bytes::from_static(b"hello");
+    let mut out = [0; 10];
+    buf.copy_to_slice(&mut out);
+}
+
+#[test]
+fn test_copy_to_slice_bytes_mut() {
+    let mut buf = BytesMut::from(&b"hello world"[..]);
+
+    let mut out = [0; 5];
+    buf.copy_to_slice(&mut out);
+    assert_eq!(b"hello", &out[..]);
+    assert_eq!(buf.chunk(), b" world");
+
+    let mut out = [0; 6];
+    buf.copy_to_slice(&mut out);
+    assert_eq!(b" world", &out[..]);
+    assert_eq!(0, buf.remaining());
+}
+
+#[test]
+#[should_panic]
+fn test_copy_to_slice_bytes_mut_panics_on_underflow() {
+    let mut buf = BytesMut::from(&b"hello"[..]);
+    let mut out = [0; 10];
+    buf.copy_to_slice(&mut out);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_copy_to_slice_cursor() {
+    use std::io::Cursor;
+
+    let mut buf = Cursor::new(b"hello world".to_vec());
+
+    let mut out = [0; 5];
+    buf.copy_to_slice(&mut out);
+    assert_eq!(b"hello", &out[..]);
+    assert_eq!(buf.chunk(), b" world");
+
+    let mut out = [0; 6];
+    buf.copy_to_slice(&mut out);
+    assert_eq!(b" world", &out[..]);
+    assert_eq!(0, buf.remaining());
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn test_copy_to_slice_cursor_panics_on_underflow() {
+    use std::io::Cursor;
+
+    let mut buf = Cursor::new(b"hello".to_vec());
+    let mut out = [0; 10];
+    buf.copy_to_slice(&mut out);
+}
+
 #[allow(unused_allocation)] // This is intentional.
 #[test]
 fn test_deref_buf_forwards() {
@@ -132,3 +229,27 @@ fn copy_to_bytes_overflow() {
 
     let _bytes = buf.copy_to_bytes(12);
 }
+
+#[test]
+fn append_to_appends_and_drains() {
+    let mut buf = &b"hello "[..];
+    let mut dst = BytesMut::from(&b"say: "[..]);
+
+    buf.append_to(&mut dst);
+
+    assert_eq!(dst, b"say: hello "[..]);
+    assert!(!buf.has_remaining());
+}
+
+#[test]
+fn append_to_bytes_into_empty_dst_does_not_copy() {
+    let mut buf = Bytes::from(b"hello world".to_vec());
+    let ptr = buf.as_ptr();
+    let mut dst = BytesMut::new();
+
+    buf.append_to(&mut dst);
+
+    assert_eq!(dst, b"hello world"[..]);
+    assert_eq!(dst.as_ptr(), ptr);
+    assert!(buf.is_empty());
+}
@@ -345,6 +345,16 @@ fn freeze_clone_unique() {
     assert_eq!(c, s);
 }
 
+#[test]
+fn freeze_with_capacity() {
+    let mut b = BytesMut::with_capacity(16);
+    b.extend_from_slice(b"abcdefgh");
+    let cap = b.capacity();
+    let (bytes, capacity) = b.freeze_with_capacity();
+    assert_eq!(bytes, &b"abcdefgh"[..]);
+    assert_eq!(capacity, cap);
+}
+
 #[test]
 fn freeze_after_advance() {
     let s = &b"abcdefgh"[..];
@@ -724,6 +734,33 @@ fn advance_past_len() {
     a.advance(20);
 }
 
+#[test]
+fn grow_uninit() {
+    let mut buf = BytesMut::with_capacity(4);
+    buf.extend_from_slice(b"ab");
+
+    unsafe {
+        let uninit = buf.grow_uninit(2);
+        assert_eq!(uninit.len(), 2);
+        uninit[0].write(b'c');
+        uninit[1].write(b'd');
+    }
+
+    assert_eq!(buf.len(), 4);
+    assert_eq!(&buf[..], b"abcd");
+
+    // Growing past the buffer's initial capacity should reallocate rather
+    // than read or write out of bounds.
+    unsafe {
+        let uninit = buf.grow_uninit(4);
+        for (i, slot) in uninit.iter_mut().enumerate() {
+            slot.write(b'0' + i as u8);
+        }
+    }
+
+    assert_eq!(&buf[..], b"abcd0123");
+}
+
 #[test]
 // Only run these tests on little endian systems. CI uses qemu for testing
 // big endian... and qemu doesn't really support threading all that well.
@@ -1223,6 +1260,24 @@ fn mut_shared_is_unique() {
     assert!(c.is_unique());
 }
 
+#[test]
+fn try_into_mut_reclaims_spare_capacity() {
+    let mut b = BytesMut::with_capacity(64);
+    b.extend_from_slice(&[1, 2, 3, 4, 5]);
+    let cap = b.capacity();
+    let ptr = b.as_ptr();
+
+    let mut reclaimed = b.freeze().try_into_mut().unwrap();
+
+    // The reclaimed `BytesMut` should see the whole backing allocation, not
+    // just the 5 bytes that were in view, so that appending within the
+    // original capacity doesn't need to reallocate.
+    assert_eq!(reclaimed.capacity(), cap);
+
+    reclaimed.extend_from_slice(&[0; 10]);
+    assert_eq!(reclaimed.as_ptr(), ptr);
+}
+
 #[test]
 fn test_bytesmut_from_bytes_static() {
     let bs = b"1b23exfcz3r";
@@ -1633,3 +1688,49 @@ fn owned_safe_drop_on_as_ref_panic() {
     assert!(result.is_err());
     assert_eq!(drop_counter.get(), 1);
 }
+
+#[test]
+fn partial_eq_distinguishes_equal_length_different_content() {
+    // Regression guard: `PartialEq for Bytes` must compare `self` against
+    // `other`, not `self` against itself. Two equal-length `Bytes` with
+    // different contents must never compare equal.
+    let a = Bytes::from_static(b"abcdefgh");
+    let b = Bytes::from_static(b"abcdefgx");
+    assert_ne!(a, b);
+    assert_eq!(a.len(), b.len());
+}
+
+#[test]
+fn from_static_same_slice_is_ptr_eq() {
+    static DATA: &[u8] = b"hello static world";
+
+    let a = Bytes::from_static(DATA);
+    let b = Bytes::from_static(DATA);
+
+    assert_eq!(a.as_ptr(), b.as_ptr());
+}
+
+#[test]
+fn as_static_returns_slice_for_static_backed_bytes() {
+    let a = Bytes::from_static(b"hello world");
+    assert_eq!(a.as_static(), Some(&b"hello world"[..]));
+
+    // Slicing a static-backed `Bytes` preserves the static backing.
+    let b = a.slice(2..5);
+    assert_eq!(b.as_static(), Some(&b"llo"[..]));
+
+    // `Bytes::new()` is static-backed too.
+    assert_eq!(Bytes::new().as_static(), Some(&b""[..]));
+}
+
+#[test]
+fn as_static_returns_none_for_non_static_backed_bytes() {
+    let owned = Bytes::from(b"hello world".to_vec());
+    assert_eq!(owned.as_static(), None);
+
+    let shared = owned.clone();
+    assert_eq!(shared.as_static(), None);
+
+    let from_owner = Bytes::from_owner(b"hello world".to_vec());
+    assert_eq!(from_owner.as_static(), None);
+}
@@ -0,0 +1,52 @@
+#![warn(rust_2018_idioms)]
+
+use bytes::{Bytes, BytesMut};
+
+#[test]
+fn hexdump_empty() {
+    let buf = Bytes::new();
+    assert_eq!("", buf.hexdump().to_string());
+}
+
+#[test]
+fn hexdump_exact_one_line() {
+    let buf: Vec<u8> = (0..16).collect();
+    let buf = Bytes::from(buf);
+
+    assert_eq!(
+        "00000000: 0001 0203 0405 0607 0809 0a0b 0c0d 0e0f  ................\n",
+        buf.hexdump().to_string(),
+    );
+}
+
+#[test]
+fn hexdump_multi_line() {
+    let buf: Vec<u8> = (0..20).collect();
+    let buf = Bytes::from(buf);
+
+    let expected = "00000000: 0001 0203 0405 0607 0809 0a0b 0c0d 0e0f  ................\n\
+                      00000010: 1011 1213                                ....\n";
+
+    assert_eq!(expected, buf.hexdump().to_string());
+}
+
+#[test]
+fn hexdump_non_printable_bytes_as_dots() {
+    let buf = Bytes::from_static(&[0x00, 0x1f, b'A', 0x7f, 0x80]);
+
+    assert_eq!(
+        "00000000: 001f 417f 80                             ..A..\n",
+        buf.hexdump().to_string(),
+    );
+}
+
+#[test]
+fn hexdump_bytes_mut_matches_bytes() {
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(b"hello world!\n");
+
+    assert_eq!(
+        Bytes::from(buf.clone()).hexdump().to_string(),
+        buf.hexdump().to_string(),
+    );
+}